@@ -4,12 +4,65 @@
 use std::error::Error;
 use std::fmt;
 
-/// Represents a parsed document
+use pulldown_cmark::{Event, Parser, Tag, TagEnd};
+use scraper::{Html, Node};
+use url::Url;
+
+/// Represents a parsed document, including whatever provenance its parser could recover
 #[derive(Debug, Clone)]
 pub struct Document {
     content: String,
     source: String,
     word_count: usize,
+    url: Option<Url>,
+    mime_type: String,
+    language: Option<String>,
+}
+
+/// Builder for `Document`, letting parsers attach whatever metadata they know about
+/// (the `url`, `mime_type`, and detected `language`) without widening `Document::new`
+pub struct DocumentBuilder {
+    content: String,
+    source: String,
+    url: Option<Url>,
+    mime_type: String,
+    language: Option<String>,
+}
+
+impl DocumentBuilder {
+    pub fn url(mut self, url: Url) -> Self {
+        self.url = Some(url);
+        self
+    }
+
+    pub fn mime_type(mut self, mime_type: impl Into<String>) -> Self {
+        self.mime_type = mime_type.into();
+        self
+    }
+
+    pub fn language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    pub fn build(self) -> Result<Document, DocumentError> {
+        if self.content.is_empty() {
+            return Err(DocumentError {
+                message: "Document content cannot be empty".to_string(),
+            });
+        }
+
+        let word_count = self.content.split_whitespace().count();
+
+        Ok(Document {
+            content: self.content,
+            source: self.source,
+            word_count,
+            url: self.url,
+            mime_type: self.mime_type,
+            language: self.language,
+        })
+    }
 }
 
 /// Error type for document operations
@@ -27,7 +80,8 @@ impl fmt::Display for DocumentError {
 impl Error for DocumentError {}
 
 impl Document {
-    /// Creates a new Document instance
+    /// Creates a new Document instance with no metadata beyond content and source.
+    /// Use `Document::builder` to attach a `url`, `mime_type`, or `language`.
     ///
     /// # Arguments
     /// * `content` - The document content
@@ -36,19 +90,26 @@ impl Document {
     /// # Returns
     /// A Result containing the Document or an error
     pub fn new(content: String, source: String) -> Result<Self, DocumentError> {
-        if content.is_empty() {
-            return Err(DocumentError {
-                message: "Document content cannot be empty".to_string(),
-            });
-        }
-
-        let word_count = content.split_whitespace().count();
+        Self::builder(content, source).build()
+    }
 
-        Ok(Document {
+    /// Starts a `DocumentBuilder`, defaulting `mime_type` to `text/plain` and
+    /// `url`/`language` to unset
+    pub fn builder(content: String, source: String) -> DocumentBuilder {
+        DocumentBuilder {
             content,
             source,
-            word_count,
-        })
+            url: None,
+            mime_type: "text/plain".to_string(),
+            language: None,
+        }
+    }
+
+    /// Returns a copy of this Document with `mime_type` replaced, for callers (like
+    /// `ParserRegistry`) that resolve the MIME type after the parser has already run
+    pub fn with_mime_type(mut self, mime_type: impl Into<String>) -> Self {
+        self.mime_type = mime_type.into();
+        self
     }
 
     pub fn content(&self) -> &str {
@@ -62,6 +123,18 @@ impl Document {
     pub fn word_count(&self) -> usize {
         self.word_count
     }
+
+    pub fn url(&self) -> Option<&Url> {
+        self.url.as_ref()
+    }
+
+    pub fn mime_type(&self) -> &str {
+        &self.mime_type
+    }
+
+    pub fn language(&self) -> Option<&str> {
+        self.language.as_deref()
+    }
 }
 
 /// Trait for document parsers
@@ -87,40 +160,548 @@ impl DocumentParser for TextParser {
     }
 }
 
-/// Utility struct for text chunking
+/// Handles HTML documents, extracting visible text rather than raw markup
+pub struct HtmlParser;
+
+impl HtmlParser {
+    /// Tags whose content should be skipped entirely rather than flattened to text.
+    /// `title` is excluded here because it is extracted separately, up front.
+    fn is_skipped(tag: &str) -> bool {
+        matches!(tag, "script" | "style" | "title")
+    }
+
+    /// Tags that should introduce a line break so unrelated blocks don't run together
+    fn is_block_level(tag: &str) -> bool {
+        matches!(
+            tag,
+            "p" | "div"
+                | "br"
+                | "li"
+                | "h1"
+                | "h2"
+                | "h3"
+                | "h4"
+                | "h5"
+                | "h6"
+                | "tr"
+                | "table"
+                | "section"
+                | "article"
+                | "header"
+                | "footer"
+                | "blockquote"
+        )
+    }
+
+    /// Walks the DOM depth-first, appending text nodes and skipping script/style subtrees
+    fn walk(node: ego_tree::NodeRef<Node>, out: &mut String) {
+        match node.value() {
+            Node::Element(element) => {
+                let tag = element.name();
+                if Self::is_skipped(tag) {
+                    return;
+                }
+
+                for child in node.children() {
+                    Self::walk(child, out);
+                }
+
+                if Self::is_block_level(tag) && !out.ends_with('\n') {
+                    out.push('\n');
+                }
+            }
+            Node::Text(text) => {
+                out.push_str(text);
+            }
+            Node::Document | Node::Fragment => {
+                for child in node.children() {
+                    Self::walk(child, out);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl DocumentParser for HtmlParser {
+    fn supports(&self, mime_type: &str) -> bool {
+        mime_type == "text/html"
+    }
+
+    fn parse(&self, buffer: &[u8], filename: &str) -> Result<Document, Box<dyn Error>> {
+        let raw = String::from_utf8(buffer.to_vec())?;
+        let document = Html::parse_document(&raw);
+
+        let title = document
+            .select(&scraper::Selector::parse("title").unwrap())
+            .next()
+            .map(|el| el.text().collect::<String>().trim().to_string())
+            .filter(|t| !t.is_empty());
+
+        let lang = document
+            .select(&scraper::Selector::parse("html").unwrap())
+            .next()
+            .and_then(|el| el.value().attr("lang"))
+            .map(str::to_string)
+            .filter(|l| !l.is_empty());
+
+        let mut text = String::new();
+        Self::walk(document.tree.root(), &mut text);
+
+        let mut content = String::new();
+        if let Some(title) = title {
+            content.push_str(&title);
+            content.push_str("\n\n");
+        }
+        for line in text.split('\n') {
+            let trimmed = line.trim();
+            if !trimmed.is_empty() {
+                content.push_str(trimmed);
+                content.push('\n');
+            }
+        }
+
+        let mut builder = Document::builder(content.trim().to_string(), filename.to_string())
+            .mime_type("text/html");
+        if let Some(lang) = lang {
+            builder = builder.language(lang);
+        }
+
+        Ok(builder.build()?)
+    }
+}
+
+/// A heading found while flattening Markdown, usable as a natural chunk boundary
+#[derive(Debug, Clone, PartialEq)]
+pub struct Section {
+    pub heading_level: u8,
+    pub heading_text: String,
+    pub byte_range: std::ops::Range<usize>,
+}
+
+/// Handles Markdown documents, flattening to text while keeping the heading outline
+pub struct MarkdownParser;
+
+impl MarkdownParser {
+    /// Flattens the document to plain text and returns the heading sections found,
+    /// each spanning from its own heading to the start of the next (or end of document)
+    pub fn parse_with_sections(
+        &self,
+        buffer: &[u8],
+        filename: &str,
+    ) -> Result<(Document, Vec<Section>), Box<dyn Error>> {
+        let raw = String::from_utf8(buffer.to_vec())?;
+
+        let mut content = String::new();
+        let mut headings: Vec<(u8, String, usize)> = Vec::new();
+        let mut in_heading = false;
+        let mut heading_text = String::new();
+
+        for (event, range) in Parser::new(&raw).into_offset_iter() {
+            match event {
+                Event::Start(Tag::Heading { level, .. }) => {
+                    while content.ends_with(' ') {
+                        content.pop();
+                    }
+                    if !content.is_empty() && !content.ends_with('\n') {
+                        content.push('\n');
+                    }
+                    in_heading = true;
+                    heading_text.clear();
+                    headings.push((level as u8, String::new(), range.start));
+                }
+                Event::End(TagEnd::Heading(_)) => {
+                    in_heading = false;
+                    if let Some(last) = headings.last_mut() {
+                        last.1 = heading_text.trim().to_string();
+                    }
+                    content.push_str(heading_text.trim());
+                    content.push('\n');
+                }
+                Event::Text(text) | Event::Code(text) => {
+                    if in_heading {
+                        heading_text.push_str(&text);
+                    } else {
+                        content.push_str(&text);
+                        content.push(' ');
+                    }
+                }
+                Event::SoftBreak | Event::HardBreak => {
+                    content.push('\n');
+                }
+                _ => {}
+            }
+        }
+
+        let sections = headings
+            .iter()
+            .enumerate()
+            .map(|(i, (level, text, start))| {
+                let end = headings
+                    .get(i + 1)
+                    .map(|(_, _, next_start)| *next_start)
+                    .unwrap_or(raw.len());
+                Section {
+                    heading_level: *level,
+                    heading_text: text.clone(),
+                    byte_range: *start..end,
+                }
+            })
+            .collect();
+
+        let document = Document::builder(content.trim().to_string(), filename.to_string())
+            .mime_type("text/markdown")
+            .build()?;
+        Ok((document, sections))
+    }
+}
+
+impl DocumentParser for MarkdownParser {
+    fn supports(&self, mime_type: &str) -> bool {
+        mime_type == "text/markdown"
+    }
+
+    fn parse(&self, buffer: &[u8], filename: &str) -> Result<Document, Box<dyn Error>> {
+        let (document, _sections) = self.parse_with_sections(buffer, filename)?;
+        Ok(document)
+    }
+}
+
+/// Dispatches a buffer to whichever registered `DocumentParser` supports its MIME type,
+/// sniffing the type from the filename extension or buffer contents when none is given
+pub struct ParserRegistry {
+    parsers: Vec<Box<dyn DocumentParser>>,
+}
+
+impl ParserRegistry {
+    /// Creates an empty registry; use `with_defaults` for the built-in parser set
+    pub fn new() -> Self {
+        ParserRegistry {
+            parsers: Vec::new(),
+        }
+    }
+
+    /// Creates a registry pre-populated with `TextParser`, `HtmlParser`, and `MarkdownParser`
+    pub fn with_defaults() -> Self {
+        let mut registry = Self::new();
+        registry.register(Box::new(TextParser));
+        registry.register(Box::new(HtmlParser));
+        registry.register(Box::new(MarkdownParser));
+        registry
+    }
+
+    /// Adds a parser to the registry; parsers registered earlier take priority when
+    /// more than one supports the same MIME type
+    pub fn register(&mut self, parser: Box<dyn DocumentParser>) {
+        self.parsers.push(parser);
+    }
+
+    /// Parses `buffer` with the first registered parser that supports `mime_hint`, or
+    /// the sniffed MIME type if no hint is given
+    pub fn parse(
+        &self,
+        buffer: &[u8],
+        filename: &str,
+        mime_hint: Option<&str>,
+    ) -> Result<Document, Box<dyn Error>> {
+        let mime_type = match mime_hint {
+            Some(mime_type) => mime_type.to_string(),
+            None => Self::sniff_mime_type(buffer, filename),
+        };
+
+        let parser = self
+            .parsers
+            .iter()
+            .find(|parser| parser.supports(&mime_type))
+            .ok_or_else(|| -> Box<dyn Error> {
+                Box::new(DocumentError {
+                    message: format!("no registered parser supports MIME type '{mime_type}'"),
+                })
+            })?;
+
+        Ok(parser.parse(buffer, filename)?.with_mime_type(mime_type))
+    }
+
+    /// Maps a filename extension to a MIME type, falling back to inspecting the
+    /// leading bytes of `buffer` when the extension is missing or unrecognized
+    fn sniff_mime_type(buffer: &[u8], filename: &str) -> String {
+        let extension = filename.rsplit('.').next().unwrap_or("").to_lowercase();
+
+        match extension.as_str() {
+            "html" | "htm" => return "text/html".to_string(),
+            "md" | "markdown" => return "text/markdown".to_string(),
+            "txt" => return "text/plain".to_string(),
+            _ => {}
+        }
+
+        let leading = String::from_utf8_lossy(&buffer[..buffer.len().min(512)]);
+        let trimmed = leading.trim_start();
+        // Compare by chars, not a fixed byte count: slicing a &str at a byte index
+        // that isn't a char boundary panics, and a multibyte character can easily
+        // land inside the first 9 bytes of an upload.
+        let leading_chars: String = trimmed.chars().take(9).collect::<String>().to_lowercase();
+        if leading_chars.starts_with("<!doctype") || leading_chars.starts_with("<html") {
+            return "text/html".to_string();
+        }
+
+        if std::str::from_utf8(buffer).is_ok() && !buffer.contains(&0) {
+            return "text/plain".to_string();
+        }
+
+        "application/octet-stream".to_string()
+    }
+}
+
+impl Default for ParserRegistry {
+    fn default() -> Self {
+        Self::with_defaults()
+    }
+}
+
+/// Splits text into overlapping, size-bounded chunks suited for embedding pipelines
 pub struct TextChunker {
     chunk_size: usize,
+    chunk_overlap: usize,
+}
+
+/// Builder for `TextChunker`, defaulting to no overlap between chunks
+#[derive(Debug, Clone)]
+pub struct TextChunkerBuilder {
+    chunk_size: usize,
+    chunk_overlap: usize,
+}
+
+impl Default for TextChunkerBuilder {
+    fn default() -> Self {
+        TextChunkerBuilder {
+            chunk_size: 1000,
+            chunk_overlap: 0,
+        }
+    }
+}
+
+impl TextChunkerBuilder {
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size;
+        self
+    }
+
+    pub fn chunk_overlap(mut self, chunk_overlap: usize) -> Self {
+        self.chunk_overlap = chunk_overlap;
+        self
+    }
+
+    pub fn build(self) -> TextChunker {
+        TextChunker {
+            chunk_size: self.chunk_size,
+            chunk_overlap: self.chunk_overlap,
+        }
+    }
 }
 
 impl TextChunker {
-    /// Creates a new TextChunker with the specified chunk size
+    /// Creates a new TextChunker with the specified chunk size and no overlap
     pub fn new(chunk_size: usize) -> Self {
-        TextChunker { chunk_size }
+        TextChunker {
+            chunk_size,
+            chunk_overlap: 0,
+        }
     }
 
-    /// Splits text into chunks of approximately chunk_size characters
+    /// Starts a `TextChunkerBuilder` for configuring chunk size and overlap together
+    pub fn builder() -> TextChunkerBuilder {
+        TextChunkerBuilder::default()
+    }
+
+    /// Splits text into chunks of at most `chunk_size` bytes, sliding each new chunk's
+    /// start back by roughly `chunk_overlap` bytes of the previous chunk's trailing
+    /// sentences so embeddings retain cross-chunk context
     pub fn chunk(&self, text: &str) -> Vec<String> {
+        let units = self.split_into_units(text);
+
         let mut chunks = Vec::new();
-        let mut current_chunk = String::new();
+        let mut current: Vec<String> = Vec::new();
+
+        for unit in units {
+            let candidate_len = Self::joined_len(&current) + unit.len() + Self::separator_len(&current);
+            if !current.is_empty() && candidate_len > self.chunk_size {
+                chunks.push(current.join(". "));
+                current = self.seed_overlap(&current, unit.len());
+            }
+            current.push(unit);
+        }
+
+        if !current.is_empty() {
+            chunks.push(current.join(". "));
+        }
+
+        chunks
+    }
+
+    /// Splits `text` on the `". "` sentence delimiter, hard-splitting any sentence that
+    /// alone exceeds `chunk_size` on word boundaries (falling back to char boundaries
+    /// for any word that is itself longer than `chunk_size`)
+    fn split_into_units(&self, text: &str) -> Vec<String> {
+        let mut units = Vec::new();
 
         for sentence in text.split(". ") {
-            if current_chunk.len() + sentence.len() > self.chunk_size {
-                if !current_chunk.is_empty() {
-                    chunks.push(current_chunk.trim().to_string());
-                    current_chunk.clear();
+            if sentence.len() > self.chunk_size {
+                units.extend(self.hard_split(sentence));
+            } else {
+                units.push(sentence.to_string());
+            }
+        }
+
+        units
+    }
+
+    /// Splits an oversized sentence on word boundaries into pieces no larger than
+    /// `chunk_size`. A word that alone exceeds `chunk_size` (a URL, a hash, any token
+    /// with no whitespace) has no word boundary to split on, so it falls back to
+    /// `split_word_by_chars` to keep the same size guarantee.
+    fn hard_split(&self, sentence: &str) -> Vec<String> {
+        let mut pieces = Vec::new();
+        let mut current = String::new();
+
+        for word in sentence.split_whitespace() {
+            if word.len() > self.chunk_size {
+                if !current.is_empty() {
+                    pieces.push(current.clone());
+                    current.clear();
                 }
+                pieces.extend(Self::split_word_by_chars(word, self.chunk_size));
+                continue;
             }
-            if !current_chunk.is_empty() {
-                current_chunk.push_str(". ");
+
+            let added = word.len() + if current.is_empty() { 0 } else { 1 };
+            if !current.is_empty() && current.len() + added > self.chunk_size {
+                pieces.push(current.clone());
+                current.clear();
             }
-            current_chunk.push_str(sentence);
+            if !current.is_empty() {
+                current.push(' ');
+            }
+            current.push_str(word);
         }
 
-        if !current_chunk.is_empty() {
-            chunks.push(current_chunk.trim().to_string());
+        if !current.is_empty() {
+            pieces.push(current);
         }
 
-        chunks
+        pieces
+    }
+
+    /// Splits a single whitespace-free word into pieces no larger than `chunk_size`,
+    /// breaking on char boundaries since there is no better split point available
+    fn split_word_by_chars(word: &str, chunk_size: usize) -> Vec<String> {
+        let mut pieces = Vec::new();
+        let mut piece = String::new();
+        let mut piece_len = 0usize;
+
+        for ch in word.chars() {
+            if piece_len + ch.len_utf8() > chunk_size && !piece.is_empty() {
+                pieces.push(piece.clone());
+                piece.clear();
+                piece_len = 0;
+            }
+            piece.push(ch);
+            piece_len += ch.len_utf8();
+        }
+
+        if !piece.is_empty() {
+            pieces.push(piece);
+        }
+
+        pieces
+    }
+
+    /// Picks the trailing sentences of `previous` whose combined length is closest to,
+    /// but not over, `chunk_overlap`, to seed the next chunk with shared context.
+    /// The seed is also capped so that `seed + ". " + next_unit` can never exceed
+    /// `chunk_size`, and no overlap is seeded at all when `chunk_overlap` is zero.
+    fn seed_overlap(&self, previous: &[String], next_unit_len: usize) -> Vec<String> {
+        if self.chunk_overlap == 0 {
+            return Vec::new();
+        }
+
+        let max_seed_len = self.chunk_size.saturating_sub(next_unit_len + 2);
+
+        let mut seed: Vec<String> = Vec::new();
+        let mut len = 0usize;
+
+        for sentence in previous.iter().rev() {
+            let added = sentence.len() + if seed.is_empty() { 0 } else { 2 };
+            if len + added > self.chunk_overlap || len + added > max_seed_len {
+                break;
+            }
+            seed.insert(0, sentence.clone());
+            len += added;
+        }
+
+        seed
+    }
+
+    fn joined_len(sentences: &[String]) -> usize {
+        if sentences.is_empty() {
+            return 0;
+        }
+        sentences.iter().map(|s| s.len()).sum::<usize>() + (sentences.len() - 1) * 2
+    }
+
+    fn separator_len(sentences: &[String]) -> usize {
+        if sentences.is_empty() {
+            0
+        } else {
+            2
+        }
+    }
+}
+
+/// Golden-file test harness, ported from rust-analyzer's `dir_tests`: walk a fixture
+/// directory, run each input through a transform, and diff the result against a
+/// committed `.expected` sibling. Set `UPDATE_EXPECT=1` to (re)write the expected files.
+#[cfg(test)]
+mod dir_tests {
+    use std::env;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    fn test_data_dir(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("test_data").join(name)
+    }
+
+    pub fn run_dir_test(dir_name: &str, extension: &str, transform: impl Fn(&str, &[u8]) -> String) {
+        let dir = test_data_dir(dir_name);
+        let update = env::var_os("UPDATE_EXPECT").is_some();
+        let mut ran_any = false;
+
+        for entry in fs::read_dir(&dir).unwrap_or_else(|e| panic!("failed to read {}: {e}", dir.display())) {
+            let path = entry.unwrap().path();
+            if path.extension().and_then(|e| e.to_str()) != Some(extension) {
+                continue;
+            }
+            ran_any = true;
+
+            let input = fs::read(&path).unwrap();
+            let file_name = path.file_name().unwrap().to_str().unwrap();
+            let actual = transform(file_name, &input);
+
+            let expected_path = path.with_extension(format!("{extension}.expected"));
+            if update {
+                fs::write(&expected_path, &actual).unwrap();
+                continue;
+            }
+
+            let expected = fs::read_to_string(&expected_path).unwrap_or_else(|_| {
+                panic!(
+                    "missing expected file {}; run with UPDATE_EXPECT=1 to generate it",
+                    expected_path.display()
+                )
+            });
+            assert_eq!(actual, expected, "mismatch for {}", path.display());
+        }
+
+        assert!(ran_any, "no '.{extension}' fixtures found in {}", dir.display());
     }
 }
 
@@ -132,6 +713,60 @@ mod tests {
     fn test_document_creation() {
         let doc = Document::new("Hello world".to_string(), "test.txt".to_string()).unwrap();
         assert_eq!(doc.word_count(), 2);
+        assert_eq!(doc.mime_type(), "text/plain");
+        assert_eq!(doc.language(), None);
+        assert_eq!(doc.url(), None);
+    }
+
+    #[test]
+    fn test_document_builder_attaches_metadata() {
+        let doc = Document::builder("Bonjour".to_string(), "greeting.html".to_string())
+            .mime_type("text/html")
+            .language("fr")
+            .url(Url::parse("https://example.com/greeting").unwrap())
+            .build()
+            .unwrap();
+        assert_eq!(doc.mime_type(), "text/html");
+        assert_eq!(doc.language(), Some("fr"));
+        assert_eq!(doc.url().unwrap().as_str(), "https://example.com/greeting");
+    }
+
+    #[test]
+    fn test_chunk_respects_chunk_size() {
+        let chunker = TextChunker::new(20);
+        let chunks = chunker.chunk("One. Two. Three. Four. Five.");
+        assert!(chunks.iter().all(|c| c.len() <= 20));
+    }
+
+    #[test]
+    fn test_chunk_overlap_shares_context() {
+        let chunker = TextChunker::builder()
+            .chunk_size(20)
+            .chunk_overlap(10)
+            .build();
+        let chunks = chunker.chunk("One. Two. Three. Four. Five.");
+        assert_eq!(
+            chunks,
+            vec!["One. Two. Three", "Two. Three. Four", "Four. Five."]
+        );
+        // "Two. Three" carries from the end of chunk 0 into the start of chunk 1 -
+        // seed_overlap pulls as many trailing sentences as fit in chunk_overlap, not just one.
+        assert!(chunks[1].starts_with("Two. Three"));
+    }
+
+    #[test]
+    fn test_chunk_hard_splits_oversized_sentence() {
+        let chunker = TextChunker::new(10);
+        let chunks = chunker.chunk("word ".repeat(10).trim());
+        assert!(chunks.iter().all(|c| c.len() <= 10));
+    }
+
+    #[test]
+    fn test_chunk_splits_whitespace_free_word_on_char_boundary() {
+        let chunker = TextChunker::new(5);
+        let chunks = chunker.chunk("abcdefghij");
+        assert!(chunks.iter().all(|c| c.len() <= 5));
+        assert_eq!(chunks, vec!["abcde", "fghij"]);
     }
 
     #[test]
@@ -139,4 +774,65 @@ mod tests {
         let result = Document::new("".to_string(), "test.txt".to_string());
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_registry_dispatches_by_mime_hint() {
+        let registry = ParserRegistry::with_defaults();
+        let doc = registry
+            .parse(b"hello world", "greeting.txt", Some("text/plain"))
+            .unwrap();
+        assert_eq!(doc.content(), "hello world");
+    }
+
+    #[test]
+    fn test_registry_sniffs_html_from_extension() {
+        let registry = ParserRegistry::with_defaults();
+        let doc = registry
+            .parse(b"<html><body>Hi</body></html>", "page.html", None)
+            .unwrap();
+        assert_eq!(doc.content(), "Hi");
+    }
+
+    #[test]
+    fn test_registry_sniffs_html_from_leading_bytes() {
+        let registry = ParserRegistry::with_defaults();
+        let doc = registry
+            .parse(b"<!DOCTYPE html><html><body>Hi</body></html>", "upload", None)
+            .unwrap();
+        assert_eq!(doc.content(), "Hi");
+    }
+
+    #[test]
+    fn test_registry_sniffs_multibyte_upload_without_panicking() {
+        let registry = ParserRegistry::with_defaults();
+        let doc = registry
+            .parse("abcdefgh😀 some text".as_bytes(), "upload", None)
+            .unwrap();
+        assert_eq!(doc.mime_type(), "text/plain");
+    }
+
+    #[test]
+    fn golden_chunker() {
+        let chunker = TextChunker::builder().chunk_size(12).chunk_overlap(6).build();
+        dir_tests::run_dir_test("chunker", "txt", |_name, input| {
+            let text = String::from_utf8(input.to_vec()).unwrap();
+            chunker.chunk(&text).join("\n---\n")
+        });
+    }
+
+    #[test]
+    fn golden_html_parser() {
+        let parser = HtmlParser;
+        dir_tests::run_dir_test("html", "html", |name, input| {
+            parser.parse(input, name).unwrap().content().to_string()
+        });
+    }
+
+    #[test]
+    fn golden_markdown_parser() {
+        let parser = MarkdownParser;
+        dir_tests::run_dir_test("markdown", "md", |name, input| {
+            parser.parse(input, name).unwrap().content().to_string()
+        });
+    }
 }